@@ -39,7 +39,8 @@
 //! [pathbuf]: macro.pathbuf.html
 //! [std_vec]: https://doc.rust-lang.org/std/macro.vec.html "Documentation for std::vec (macro)"
 
-use std::path::PathBuf;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 
 /// Creates a [`PathBuf`] containing the arguments.
 ///
@@ -94,34 +95,225 @@ mod tests {
     }
 }
 
-/// A safe wrapper for a path with only a single component.
+/// A borrowed, safe view of a path with only a single component.
 /// This prevents path traversal attacks.
 ///
 /// It just allows a single normal path element and no parent, root directory or prefix like `C:`.
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+///
+/// This is the borrowed counterpart to [`SinglePathComponentBuf`], in the same
+/// way that [`Path`] is the borrowed counterpart to [`PathBuf`]. Validating an
+/// existing `&Path` through [`SinglePathComponent::new`] does not allocate.
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(transparent)]
 pub struct SinglePathComponent {
-    path: PathBuf,
+    path: Path,
 }
 
 impl SinglePathComponent {
-    /// It creates the wrapped `PathComponent` if it's valid.
+    /// It borrows the path as a `SinglePathComponent` if it's valid.
     /// Otherwise it will return `None`.
     ///
+    /// This does not allocate; the returned reference borrows the input.
+    ///
     /// ```
     /// # use pathbuf::SinglePathComponent;
     /// # #[cfg(unix)]
     /// # {
-    /// let some_valid_folder: SinglePathComponent = SinglePathComponent::new("foo").unwrap();
-    /// let some_valid_file: SinglePathComponent = SinglePathComponent::new("bar.txt").unwrap();
+    /// let some_valid_folder = SinglePathComponent::new("foo").unwrap();
+    /// let some_valid_file = SinglePathComponent::new("bar.txt").unwrap();
     /// assert!(SinglePathComponent::new("/etc/shadow").is_none());
     /// # }
     /// ```
+    pub fn new<S: AsRef<Path> + ?Sized>(component: &S) -> Option<&SinglePathComponent> {
+        let path = component.as_ref();
+
+        Self::is_valid(path).then(|| Self::new_unchecked(path))
+    }
+
+    /// Like [`SinglePathComponent::new`], but additionally requires the
+    /// component to satisfy every predicate enabled in `rules`.
+    ///
+    /// A component is accepted only if it is a single normal component *and*
+    /// the [`PathRules`] policy permits it. [`PathRules::UNRESTRICTED`] adds no
+    /// checks, so it behaves exactly like [`SinglePathComponent::new`].
+    ///
+    /// # `fs-checks` caveat
+    ///
+    /// This does *not* apply [`PathRules::OWNER_ONLY`] or
+    /// [`PathRules::NOT_ACCESSIBLE_BY_OTHERS`]. Those checks stat a real file,
+    /// but `component` here is the bare untrusted element, not yet joined onto
+    /// whatever trusted base it belongs under (e.g. in [`pathbuf_safe!`]), so
+    /// there usually is no real file at that path yet to stat. Once you've
+    /// built the final joined path, pass the same `rules` to
+    /// [`PathRules::permits_joined`] to apply those two checks to it.
+    ///
+    /// ```
+    /// # use pathbuf::{PathRules, SinglePathComponent};
+    /// # #[cfg(unix)]
+    /// # {
+    /// assert!(SinglePathComponent::new_with_rules("foo.txt", PathRules::FORBID_HIDDEN).is_some());
+    /// assert!(SinglePathComponent::new_with_rules(".hidden", PathRules::FORBID_HIDDEN).is_none());
+    /// # }
+    /// ```
+    pub fn new_with_rules<S: AsRef<Path> + ?Sized>(
+        component: &S,
+        rules: PathRules,
+    ) -> Option<&SinglePathComponent> {
+        let path = component.as_ref();
+
+        (Self::is_valid(path) && rules.permits(path)).then(|| Self::new_unchecked(path))
+    }
+
+    /// Like [`SinglePathComponent::new_with_rules`], but additionally requires
+    /// the component's extension to case-insensitively match one of
+    /// `allowed_extensions`.
+    ///
+    /// An allowlist of extensions carries data, not just a yes/no toggle, so it
+    /// can't be expressed as a [`PathRules`] flag (a bitflag is just a bit);
+    /// this constructor takes the allowlist directly instead. A component with
+    /// no extension, or one not present in `allowed_extensions`, is rejected.
+    ///
+    /// ```
+    /// # use pathbuf::{PathRules, SinglePathComponent};
+    /// # #[cfg(unix)]
+    /// # {
+    /// let allowed = ["txt", "md"];
+    /// assert!(
+    ///     SinglePathComponent::new_with_allowed_extensions("foo.txt", PathRules::UNRESTRICTED, &allowed)
+    ///         .is_some()
+    /// );
+    /// assert!(
+    ///     SinglePathComponent::new_with_allowed_extensions("foo.exe", PathRules::UNRESTRICTED, &allowed)
+    ///         .is_none()
+    /// );
+    /// assert!(
+    ///     SinglePathComponent::new_with_allowed_extensions("foo", PathRules::UNRESTRICTED, &allowed)
+    ///         .is_none()
+    /// );
+    /// # }
+    /// ```
+    pub fn new_with_allowed_extensions<S: AsRef<Path> + ?Sized>(
+        component: &S,
+        rules: PathRules,
+        allowed_extensions: &[&str],
+    ) -> Option<&SinglePathComponent> {
+        let path = component.as_ref();
+
+        if !(Self::is_valid(path) && rules.permits(path)) {
+            return None;
+        }
+
+        let extension = path.extension()?.to_str()?;
+        allowed_extensions
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+            .then(|| Self::new_unchecked(path))
+    }
+
+    /// Like [`SinglePathComponent::new`], but additionally validates the raw
+    /// bytes of the component against a *platform-independent* ruleset.
+    ///
+    /// The component is rejected if it is empty, is `.` or `..`, or contains a
+    /// `/`, `\` or NUL byte — regardless of the current platform. On Windows
+    /// `foo/bar` parses as a single normal component, but it is rejected here.
+    /// The component must also be a single normal component under the host
+    /// OS's parsing, so a Windows drive prefix such as `C:` is rejected too.
+    ///
+    /// ```
+    /// # use pathbuf::SinglePathComponent;
+    /// assert!(SinglePathComponent::new_portable("foo.txt").is_some());
+    /// assert!(SinglePathComponent::new_portable("foo/bar").is_none());
+    /// assert!(SinglePathComponent::new_portable("..").is_none());
+    /// ```
+    pub fn new_portable<S: AsRef<Path> + ?Sized>(component: &S) -> Option<&SinglePathComponent> {
+        let path = component.as_ref();
+
+        (Self::is_portable(path.as_os_str()) && Self::is_valid(path)).then(|| Self::new_unchecked(path))
+    }
+
+    /// Returns whether `name` is a valid component under the portable ruleset.
+    fn is_portable(name: &OsStr) -> bool {
+        let name = name.to_string_lossy();
+
+        !(name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', '\0']))
+    }
+
+    /// Wraps a `&Path` without checking its validity.
+    ///
+    /// The caller must guarantee the path is a single normal component.
+    fn new_unchecked(path: &Path) -> &SinglePathComponent {
+        // SAFETY: `SinglePathComponent` is `#[repr(transparent)]` over `Path`,
+        // so the two have the same layout and a `&Path` may be reinterpreted
+        // as a `&SinglePathComponent`.
+        unsafe { &*(path as *const Path as *const SinglePathComponent) }
+    }
+
+    fn is_valid(path: &Path) -> bool {
+        use std::path::Component;
+
+        let mut components = path.components();
+        matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        )
+    }
+}
+
+impl std::ops::Deref for SinglePathComponent {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for SinglePathComponent {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ToOwned for SinglePathComponent {
+    type Owned = SinglePathComponentBuf;
+
+    fn to_owned(&self) -> SinglePathComponentBuf {
+        SinglePathComponentBuf {
+            path: self.path.to_path_buf(),
+        }
+    }
+}
+
+/// An owned, safe wrapper for a path with only a single component.
+/// This prevents path traversal attacks.
+///
+/// It just allows a single normal path element and no parent, root directory or prefix like `C:`.
+///
+/// This is the owned counterpart to [`SinglePathComponent`], mirroring the
+/// [`PathBuf`]/[`Path`] split.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct SinglePathComponentBuf {
+    path: PathBuf,
+}
+
+impl SinglePathComponentBuf {
+    /// It creates the wrapped `SinglePathComponentBuf` if it's valid.
+    /// Otherwise it will return `None`.
+    ///
+    /// ```
+    /// # use pathbuf::SinglePathComponentBuf;
+    /// # #[cfg(unix)]
+    /// # {
+    /// let some_valid_folder: SinglePathComponentBuf = SinglePathComponentBuf::new("foo").unwrap();
+    /// let some_valid_file: SinglePathComponentBuf = SinglePathComponentBuf::new("bar.txt").unwrap();
+    /// assert!(SinglePathComponentBuf::new("/etc/shadow").is_none());
+    /// # }
+    /// ```
     pub fn new<S: Into<PathBuf>>(component: S) -> Option<Self> {
         let component = Self {
             path: component.into(),
         };
 
-        component.is_valid().then_some(component)
+        SinglePathComponent::is_valid(&component.path).then_some(component)
     }
 
     #[cfg(feature = "sanitise")]
@@ -129,12 +321,12 @@ impl SinglePathComponent {
     /// Unless there is a bug in the sanitisation then it would `panic`.
     ///
     /// ```
-    /// # use pathbuf::SinglePathComponent;
+    /// # use pathbuf::SinglePathComponentBuf;
     /// # #[cfg(unix)]
     /// # {
     /// assert_eq!(
-    ///     SinglePathComponent::with_sanitise("/etc/shadow"),
-    ///     SinglePathComponent::new("etc_shadow").unwrap(),
+    ///     SinglePathComponentBuf::with_sanitise("/etc/shadow"),
+    ///     SinglePathComponentBuf::new("etc_shadow").unwrap(),
     /// );
     /// # }
     /// ```
@@ -150,19 +342,418 @@ impl SinglePathComponent {
             )
         })
     }
+}
+
+impl std::ops::Deref for SinglePathComponentBuf {
+    type Target = SinglePathComponent;
+
+    fn deref(&self) -> &Self::Target {
+        SinglePathComponent::new_unchecked(&self.path)
+    }
+}
+
+impl AsRef<Path> for SinglePathComponentBuf {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::borrow::Borrow<SinglePathComponent> for SinglePathComponentBuf {
+    fn borrow(&self) -> &SinglePathComponent {
+        self
+    }
+}
+
+/// An iterator over the components of a [`Path`] as validated
+/// [`SinglePathComponent`]s.
+///
+/// It walks [`Path::components`], skipping `CurDir` (`.`) the same way
+/// [`MultiComponentPathBuf`] and [`resolve_within`] do, and yields `Some` for
+/// each normal component. On the first component that is neither normal nor
+/// `CurDir` (a root, prefix or parent directory) it yields `None` and
+/// *fuses*: every call after that one also returns `None`, ending iteration
+/// there and then. This is deliberate — in a traversal-defense API,
+/// `.flatten()` or `.filter_map(|c| c)` over the raw per-component results
+/// must not be able to silently drop the offending `..` and stitch the
+/// components around it back together into a path that looks safe.
+/// Collecting into an `Option<Vec<_>>` (e.g. via [`collect_validated`])
+/// validates a whole path in one call, short-circuiting on the first
+/// offending component.
+///
+/// ```
+/// # use pathbuf::SafeComponents;
+/// # use std::path::Path;
+/// # #[cfg(unix)]
+/// # {
+/// let parts: Option<Vec<_>> = SafeComponents::new(Path::new("a/b/c")).collect();
+/// assert_eq!(parts.unwrap().len(), 3);
+/// assert!(SafeComponents::new(Path::new("a/../b")).collect_validated().is_none());
+///
+/// // A harmless leading `.` is skipped rather than treated as an escape:
+/// assert_eq!(SafeComponents::new(Path::new("./a/b")).collect_validated().unwrap().len(), 2);
+///
+/// // The iterator fuses at the offending component instead of skipping it:
+/// // `b` is never yielded, so `.flatten()` cannot rebuild `a/b`.
+/// let mut it = SafeComponents::new(Path::new("a/../b"));
+/// assert!(it.next().unwrap().is_some());
+/// assert!(it.next().unwrap().is_none());
+/// assert!(it.next().is_none());
+/// # }
+/// ```
+///
+/// [`collect_validated`]: SafeComponents::collect_validated
+pub struct SafeComponents<'a> {
+    inner: std::path::Components<'a>,
+    fused: bool,
+}
+
+impl<'a> SafeComponents<'a> {
+    /// Creates a new iterator over the components of `path`.
+    pub fn new(path: &'a Path) -> Self {
+        SafeComponents {
+            inner: path.components(),
+            fused: false,
+        }
+    }
+
+    /// Validates the whole path at once, returning every normal component or
+    /// `None` as soon as a root, prefix or parent directory component is
+    /// encountered. `.` components are skipped.
+    pub fn collect_validated(self) -> Option<Vec<&'a SinglePathComponent>> {
+        self.collect()
+    }
+}
+
+impl<'a> Iterator for SafeComponents<'a> {
+    type Item = Option<&'a SinglePathComponent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use std::path::Component;
+
+        if self.fused {
+            return None;
+        }
+
+        loop {
+            match self.inner.next()? {
+                Component::CurDir => continue,
+                Component::Normal(name) => {
+                    return Some(Some(SinglePathComponent::new_unchecked(Path::new(name))));
+                }
+                _ => {
+                    self.fused = true;
+                    return Some(None);
+                }
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for SafeComponents<'_> {}
+
+/// Extends [`Path`] with [`SafePathExt::safe_components`], a validating
+/// counterpart to [`Path::components`].
+///
+/// ```
+/// # use pathbuf::SafePathExt;
+/// # use std::path::Path;
+/// # #[cfg(unix)]
+/// # {
+/// let all_normal = Path::new("a/b/c").safe_components().all(|c| c.is_some());
+/// assert!(all_normal);
+/// # }
+/// ```
+pub trait SafePathExt {
+    /// Iterates over the components of the path as validated
+    /// [`SinglePathComponent`]s.
+    fn safe_components(&self) -> SafeComponents<'_>;
+}
+
+impl SafePathExt for Path {
+    fn safe_components(&self) -> SafeComponents<'_> {
+        SafeComponents::new(self)
+    }
+}
+
+bitflags::bitflags! {
+    /// A policy describing which otherwise-valid components the safe
+    /// constructors should reject.
+    ///
+    /// Each flag is an independent predicate over the component's name; a
+    /// component is accepted only when it satisfies every enabled predicate.
+    /// [`PathRules::UNRESTRICTED`] (the empty set) enables no extra checks, so
+    /// validation reduces to the single-normal-component rule.
+    ///
+    /// An allowlist of extensions can't be a flag here, since a bitflag carries
+    /// no data — see [`SinglePathComponent::new_with_allowed_extensions`] for
+    /// that check instead.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct PathRules: u32 {
+        /// Enable no extra checks beyond the single-component requirement.
+        const UNRESTRICTED = 0;
+        /// Forbid hidden names, i.e. names beginning with a `.`.
+        const FORBID_HIDDEN = 1 << 0;
+        /// Forbid reserved Windows device names such as `CON`, `NUL` or `COM1`.
+        const FORBID_WINDOWS_RESERVED = 1 << 1;
+        /// Forbid names containing control characters (Unicode category `Cc`,
+        /// i.e. the ASCII controls and U+0080 to U+009F).
+        const FORBID_CONTROL_CHARS = 1 << 2;
+        /// Forbid names ending in a `.` or a space, which Windows trims.
+        const FORBID_TRAILING_DOT_OR_SPACE = 1 << 3;
+        /// Require the component to be valid under the platform-independent
+        /// ruleset (see [`SinglePathComponent::new_portable`]).
+        const PORTABLE = 1 << 6;
+        /// Require the component to be owned by the current user.
+        ///
+        /// Unlike the other flags, this is not checked by
+        /// [`SinglePathComponent::new_with_rules`] — it stats a real file, and
+        /// the bare untrusted component usually isn't one yet. Apply it to the
+        /// final joined path with [`PathRules::permits_joined`] instead.
+        #[cfg(feature = "fs-checks")]
+        const OWNER_ONLY = 1 << 4;
+        /// Require the component to not be accessible by other users.
+        ///
+        /// Checked the same way as [`PathRules::OWNER_ONLY`]: via
+        /// [`PathRules::permits_joined`] against the final joined path, not by
+        /// [`SinglePathComponent::new_with_rules`].
+        #[cfg(feature = "fs-checks")]
+        const NOT_ACCESSIBLE_BY_OTHERS = 1 << 5;
+    }
+}
+
+impl PathRules {
+    /// Returns whether `path` (already known to be a single normal component)
+    /// satisfies every enabled predicate.
+    fn permits(self, path: &Path) -> bool {
+        if self.contains(PathRules::PORTABLE) && !SinglePathComponent::is_portable(path.as_os_str())
+        {
+            return false;
+        }
+
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy(),
+            None => return false,
+        };
+
+        if self.contains(PathRules::FORBID_HIDDEN) && name.starts_with('.') {
+            return false;
+        }
+
+        if self.contains(PathRules::FORBID_WINDOWS_RESERVED) && is_windows_reserved(&name) {
+            return false;
+        }
+
+        if self.contains(PathRules::FORBID_CONTROL_CHARS)
+            && name.chars().any(|c| c.is_control())
+        {
+            return false;
+        }
+
+        if self.contains(PathRules::FORBID_TRAILING_DOT_OR_SPACE)
+            && name.ends_with(['.', ' '])
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Applies the `fs-checks` flags in this policy — [`PathRules::OWNER_ONLY`]
+    /// and [`PathRules::NOT_ACCESSIBLE_BY_OTHERS`] — to `path`.
+    ///
+    /// Unlike [`PathRules::permits`] (used by
+    /// [`SinglePathComponent::new_with_rules`]), `path` here is expected to be
+    /// the real, fully joined path on disk, since these two flags stat it.
+    /// Call this once the untrusted component has been validated and joined
+    /// onto its trusted base, e.g. after [`pathbuf_safe!`].
+    ///
+    /// ```
+    /// # use pathbuf::PathRules;
+    /// # #[cfg(all(feature = "fs-checks", unix))]
+    /// # {
+    /// let joined_path = std::path::Path::new("/tmp");
+    /// let _owned_by_current_user = PathRules::OWNER_ONLY.permits_joined(joined_path);
+    /// # }
+    /// ```
+    #[cfg(feature = "fs-checks")]
+    pub fn permits_joined(self, path: &Path) -> bool {
+        if self.contains(PathRules::OWNER_ONLY) && !is_owned_by_current_user(path) {
+            return false;
+        }
+
+        if self.contains(PathRules::NOT_ACCESSIBLE_BY_OTHERS) && is_accessible_by_others(path) {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Returns whether `name` (case-insensitively, ignoring any extension) is a
+/// reserved Windows device name.
+fn is_windows_reserved(name: &str) -> bool {
+    const RESERVED: &[&str] = &[
+        "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
+        "COM8", "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+    ];
+
+    let stem = name.split('.').next().unwrap_or(name);
+    RESERVED
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+}
+
+#[cfg(all(feature = "fs-checks", unix))]
+fn is_owned_by_current_user(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    // SAFETY: `getuid` is always safe to call and cannot fail.
+    let uid = unsafe { libc::getuid() };
+    std::fs::metadata(path)
+        .map(|metadata| metadata.uid() == uid)
+        .unwrap_or(false)
+}
+
+#[cfg(all(feature = "fs-checks", unix))]
+fn is_accessible_by_others(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::metadata(path)
+        .map(|metadata| metadata.mode() & 0o007 != 0)
+        .unwrap_or(true)
+}
+
+#[cfg(all(feature = "fs-checks", not(unix)))]
+fn is_owned_by_current_user(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(all(feature = "fs-checks", not(unix)))]
+fn is_accessible_by_others(_path: &Path) -> bool {
+    true
+}
+
+/// How [`resolve_within`] handles a `..` that would rise above the jail root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JailMode {
+    /// Reject the whole path by returning `None`.
+    Reject,
+    /// Clamp at the root, silently dropping the offending `..`.
+    Clamp,
+}
+
+/// Lexically resolves `untrusted` against `root`, guaranteeing the result never
+/// escapes `root`.
+///
+/// Unlike [`SinglePathComponent`], this accepts `..` in the untrusted input, but
+/// resolves it purely lexically — without touching the filesystem — so the
+/// returned path always stays within `root`. Any `RootDir` or `Prefix` makes the
+/// whole input invalid, and a `..` that would climb above `root` returns `None`.
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::resolve_within;
+/// # #[cfg(unix)]
+/// # {
+/// assert_eq!(resolve_within("/srv", "a/../b/c").unwrap(), PathBuf::from("/srv/b/c"));
+/// assert!(resolve_within("/srv", "../etc/shadow").is_none());
+/// # }
+/// ```
+pub fn resolve_within<R: AsRef<Path>, P: AsRef<Path>>(root: R, untrusted: P) -> Option<PathBuf> {
+    resolve_within_with_mode(root, untrusted, JailMode::Reject)
+}
+
+/// Like [`resolve_within`], but lets the caller choose how an escaping `..` is
+/// handled via [`JailMode`].
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::{resolve_within_with_mode, JailMode};
+/// # #[cfg(unix)]
+/// # {
+/// assert_eq!(
+///     resolve_within_with_mode("/srv", "../../a", JailMode::Clamp).unwrap(),
+///     PathBuf::from("/srv/a"),
+/// );
+/// # }
+/// ```
+pub fn resolve_within_with_mode<R: AsRef<Path>, P: AsRef<Path>>(
+    root: R,
+    untrusted: P,
+    mode: JailMode,
+) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let mut stack = Vec::new();
+    for component in untrusted.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::Normal(part) => stack.push(part),
+            Component::ParentDir => {
+                if stack.pop().is_none() && mode == JailMode::Reject {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+
+    let mut result = root.as_ref().to_path_buf();
+    for part in stack {
+        result.push(part);
+    }
+
+    Some(result)
+}
+
+/// A safe wrapper for a relative path made up of one or more components.
+///
+/// Where [`SinglePathComponent`] admits exactly one component, this admits a
+/// whole relative subpath such as `assets/img/logo.png`. It is valid only if
+/// every component is a normal path element (or a `.`) — no parent directory,
+/// root directory or prefix like `C:` anywhere — so it still cannot escape a
+/// trusted base it is joined onto.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct MultiComponentPathBuf {
+    path: PathBuf,
+}
+
+impl MultiComponentPathBuf {
+    /// It creates the wrapped path if it's valid.
+    /// Otherwise it will return `None`.
+    ///
+    /// ```
+    /// # use pathbuf::MultiComponentPathBuf;
+    /// # #[cfg(unix)]
+    /// # {
+    /// let subpath: MultiComponentPathBuf = MultiComponentPathBuf::new("assets/img/logo.png").unwrap();
+    /// assert!(MultiComponentPathBuf::new("../secret").is_none());
+    /// assert!(MultiComponentPathBuf::new("/etc/shadow").is_none());
+    /// # }
+    /// ```
+    pub fn new<S: Into<PathBuf>>(path: S) -> Option<Self> {
+        let path = Self { path: path.into() };
+
+        path.is_valid().then_some(path)
+    }
 
     fn is_valid(&self) -> bool {
         use std::path::Component;
 
-        let mut components = self.path.components();
-        matches!(
-            (components.next(), components.next()),
-            (Some(Component::Normal(_)), None)
-        )
+        let mut saw_component = false;
+        for component in self.path.components() {
+            match component {
+                Component::Normal(_) => saw_component = true,
+                Component::CurDir => {}
+                _ => return false,
+            }
+        }
+
+        saw_component
     }
 }
 
-impl std::ops::Deref for SinglePathComponent {
+impl std::ops::Deref for MultiComponentPathBuf {
     type Target = std::path::Path;
 
     fn deref(&self) -> &Self::Target {
@@ -170,7 +761,7 @@ impl std::ops::Deref for SinglePathComponent {
     }
 }
 
-impl AsRef<std::path::Path> for SinglePathComponent {
+impl AsRef<std::path::Path> for MultiComponentPathBuf {
     fn as_ref(&self) -> &std::path::Path {
         &self.path
     }
@@ -190,12 +781,35 @@ impl AsRef<std::path::Path> for SinglePathComponent {
 /// assert_eq!(path, pathbuf!["foo", "bar.txt"])
 /// # }
 /// ```
+///
+/// The borrowed [`SinglePathComponent`] can be a slice of an existing path, so
+/// validating and pushing avoids an allocation in hot loops.
 pub trait PushPathComponent {
-    fn push_component(&mut self, component: SinglePathComponent);
+    fn push_component(&mut self, component: &SinglePathComponent);
+
+    /// This allows to push a validated [`MultiComponentPathBuf`] relative
+    /// subpath onto a [`std::path::PathBuf`].
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// # use pathbuf::{pathbuf, MultiComponentPathBuf, PushPathComponent};
+    /// # #[cfg(unix)]
+    /// # {
+    /// let mut path = PathBuf::new();
+    /// path.push_multi_component(&MultiComponentPathBuf::new("foo/bar").unwrap());
+    ///
+    /// assert_eq!(path, pathbuf!["foo", "bar"])
+    /// # }
+    /// ```
+    fn push_multi_component(&mut self, component: &MultiComponentPathBuf);
 }
 
 impl PushPathComponent for PathBuf {
-    fn push_component(&mut self, component: SinglePathComponent) {
+    fn push_component(&mut self, component: &SinglePathComponent) {
+        self.push(component);
+    }
+
+    fn push_multi_component(&mut self, component: &MultiComponentPathBuf) {
         self.push(component);
     }
 }
@@ -231,8 +845,49 @@ impl PushPathComponent for PathBuf {
 /// );
 /// # }
 /// ```
+///
+/// A [`PathRules`] policy can be threaded through every component with the
+/// `rules` form.
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::{pathbuf_safe, PathRules};
+/// #
+/// # #[cfg(unix)]
+/// # {
+/// let user_input = "foo.txt";
+/// assert_eq!(
+///     pathbuf_safe![rules PathRules::FORBID_HIDDEN; "tmp", user_input].unwrap(),
+///     PathBuf::from("tmp/foo.txt"),
+/// );
+/// assert!(pathbuf_safe![rules PathRules::FORBID_HIDDEN; "tmp", ".secret"].is_none());
+/// # }
+/// ```
 #[macro_export]
 macro_rules! pathbuf_safe {
+    (rules $rules:expr; $( $part:expr ),* ) => {{
+        use std::path::PathBuf;
+        use $crate::PushPathComponent;
+
+        let rules = $rules;
+        let mut temp = Some(PathBuf::with_capacity( $( std::mem::size_of_val($part) + )* 0));
+
+        $(
+            temp = temp.and_then(|mut tmp_path| {
+                let part = $part;
+                let component = $crate::SinglePathComponent::new_with_rules(&part, rules)?;
+                tmp_path.push_component(component);
+                Some(tmp_path)
+            });
+        )*
+
+        temp
+    }};
+
+    (rules $rules:expr; $( $part:expr, )* ) => {{
+        $crate::pathbuf_safe![rules $rules; $($part),*]
+    }};
+
     ( $( $part:expr ),* ) => {{
         use std::path::PathBuf;
         use $crate::PushPathComponent;
@@ -241,7 +896,8 @@ macro_rules! pathbuf_safe {
 
         $(
             temp = temp.and_then(|mut tmp_path| {
-                let component = $crate::SinglePathComponent::new($part)?;
+                let part = $part;
+                let component = $crate::SinglePathComponent::new(&part)?;
                 tmp_path.push_component(component);
                 Some(tmp_path)
             });
@@ -261,7 +917,8 @@ macro_rules! pathbuf_safe {
         });
         $(
             temp = temp.and_then(|mut tmp_path| {
-                let component = $crate::SinglePathComponent::new($part)?;
+                let part = $part;
+                let component = $crate::SinglePathComponent::new(&part)?;
                 tmp_path.push_component(component);
                 Some(tmp_path)
             });
@@ -278,3 +935,236 @@ macro_rules! pathbuf_safe {
         $crate::pathbuf_safe![allow $($part),*]
     }};
 }
+
+/// Creates a [`PathBuf`] from multi-component relative subpaths without allowing path traversal.
+///
+/// Each part may be a whole relative subpath (e.g. `assets/img/logo.png`); it is
+/// accepted only if every one of its components is normal (or a `.`), so it
+/// cannot escape.
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::pathbuf_multi;
+/// #
+/// # #[cfg(unix)]
+/// # {
+/// let user_input = "img/logo.png";
+/// assert_eq!(pathbuf_multi!["assets", user_input].unwrap(), PathBuf::from("assets/img/logo.png"));
+/// let user_input = "../../etc/shadow";
+/// assert!(pathbuf_multi!["assets", user_input].is_none());
+/// # }
+/// ```
+///
+/// When the first part is trusted, the `allow` keyword can be used.
+/// It allows the usage of multiple components and the root.
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::pathbuf_multi;
+/// #
+/// # #[cfg(unix)]
+/// # {
+/// let user_input = "img/logo.png";
+/// assert_eq!(
+///     pathbuf_multi![allow "/var/www", user_input].unwrap(),
+///     PathBuf::from("/var/www/img/logo.png"),
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pathbuf_multi {
+    ( $( $part:expr ),* ) => {{
+        use std::path::PathBuf;
+        use $crate::PushPathComponent;
+
+        let mut temp = Some(PathBuf::with_capacity( $( std::mem::size_of_val($part) + )* 0));
+
+        $(
+            temp = temp.and_then(|mut tmp_path| {
+                let component = $crate::MultiComponentPathBuf::new($part)?;
+                tmp_path.push_multi_component(&component);
+                Some(tmp_path)
+            });
+        )*
+
+        temp
+    }};
+    (allow $first:expr, $( $part:expr ),* ) => {{
+        use std::path::PathBuf;
+        use $crate::PushPathComponent;
+
+        let mut temp = Some(PathBuf::with_capacity( $( std::mem::size_of_val($part) + )* 0));
+
+        temp = temp.map(|mut tmp_path| {
+            tmp_path.push($first);
+            tmp_path
+        });
+        $(
+            temp = temp.and_then(|mut tmp_path| {
+                let component = $crate::MultiComponentPathBuf::new($part)?;
+                tmp_path.push_multi_component(&component);
+                Some(tmp_path)
+            });
+        )*
+
+        temp
+    }};
+
+    ($( $part:expr, )*) => {{
+        $crate::pathbuf_multi![$($part),*]
+    }};
+
+    (allow $( $part:expr, )*) => {{
+        $crate::pathbuf_multi![allow $($part),*]
+    }};
+}
+
+/// Creates a [`PathBuf`] by lexically resolving untrusted parts within a trusted `root`.
+///
+/// The first argument is the trusted jail root; every following part is
+/// untrusted and may contain `..`, but the result can never escape `root`.
+/// It returns `None` if a part contains a root directory or prefix, or if a
+/// `..` would climb above the jail. See [`resolve_within`].
+///
+/// ```
+/// # use std::path::PathBuf;
+/// # use pathbuf::pathbuf_jailed;
+/// #
+/// # #[cfg(unix)]
+/// # {
+/// let user_input = "a/../b.txt";
+/// assert_eq!(pathbuf_jailed!["/srv", user_input].unwrap(), PathBuf::from("/srv/b.txt"));
+/// let user_input = "../../etc/shadow";
+/// assert!(pathbuf_jailed!["/srv", user_input].is_none());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pathbuf_jailed {
+    ( $root:expr $(, $part:expr )* $(,)? ) => {{
+        let mut untrusted = std::path::PathBuf::new();
+
+        $(
+            untrusted.push($part);
+        )*
+
+        $crate::resolve_within($root, untrusted)
+    }};
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod safety_tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn resolve_within_rejects_escape_via_nested_parent_dirs() {
+        assert!(resolve_within("/srv", "a/../../x").is_none());
+        assert!(resolve_within("/srv", "../x").is_none());
+        assert!(resolve_within("/srv", "a/../../../x").is_none());
+    }
+
+    #[test]
+    fn resolve_within_allows_parent_dirs_that_stay_inside_the_jail() {
+        assert_eq!(
+            resolve_within("/srv", "a/b/../c").unwrap(),
+            PathBuf::from("/srv/a/c")
+        );
+    }
+
+    #[test]
+    fn resolve_within_rejects_root_in_untrusted_input() {
+        assert!(resolve_within("/srv", "/etc/shadow").is_none());
+    }
+
+    #[test]
+    fn resolve_within_handles_empty_and_current_dir_input() {
+        assert_eq!(resolve_within("/srv", "").unwrap(), PathBuf::from("/srv"));
+        assert_eq!(resolve_within("/srv", ".").unwrap(), PathBuf::from("/srv"));
+    }
+
+    #[test]
+    fn jail_mode_reject_and_clamp_agree_when_nothing_escapes() {
+        let reject = resolve_within_with_mode("/srv", "a/../b", JailMode::Reject);
+        let clamp = resolve_within_with_mode("/srv", "a/../b", JailMode::Clamp);
+        assert_eq!(reject, clamp);
+        assert_eq!(reject.unwrap(), PathBuf::from("/srv/b"));
+    }
+
+    #[test]
+    fn jail_mode_reject_and_clamp_diverge_on_escape() {
+        assert!(resolve_within_with_mode("/srv", "../../x", JailMode::Reject).is_none());
+        assert_eq!(
+            resolve_within_with_mode("/srv", "../../x", JailMode::Clamp).unwrap(),
+            PathBuf::from("/srv/x")
+        );
+    }
+
+    #[test]
+    fn new_portable_rejects_interior_separators_and_nul() {
+        assert!(SinglePathComponent::new_portable("foo/bar").is_none());
+        assert!(SinglePathComponent::new_portable("foo\\bar").is_none());
+        assert!(SinglePathComponent::new_portable("foo\0bar").is_none());
+        assert!(SinglePathComponent::new_portable("").is_none());
+        assert!(SinglePathComponent::new_portable(".").is_none());
+        assert!(SinglePathComponent::new_portable("..").is_none());
+        assert!(SinglePathComponent::new_portable("foo.txt").is_some());
+    }
+
+    #[test]
+    fn forbid_windows_reserved_matches_case_insensitively_and_with_extension() {
+        let rules = PathRules::FORBID_WINDOWS_RESERVED;
+        assert!(SinglePathComponent::new_with_rules("CON", rules).is_none());
+        assert!(SinglePathComponent::new_with_rules("con", rules).is_none());
+        assert!(SinglePathComponent::new_with_rules("com1", rules).is_none());
+        assert!(SinglePathComponent::new_with_rules("COM1.txt", rules).is_none());
+        assert!(SinglePathComponent::new_with_rules("console", rules).is_some());
+        assert!(SinglePathComponent::new_with_rules("normal.txt", rules).is_some());
+    }
+
+    #[test]
+    fn safe_components_fuses_at_first_invalid_component() {
+        let mut it = SafeComponents::new(Path::new("a/../b"));
+        assert!(it.next().unwrap().is_some());
+        assert!(matches!(it.next(), Some(None)));
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn safe_components_skips_leading_cur_dir_instead_of_fusing() {
+        let parts = SafeComponents::new(Path::new("./a/b")).collect_validated();
+        assert_eq!(parts.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn safe_components_flatten_does_not_rejoin_around_the_escape() {
+        let names: Vec<_> = SafeComponents::new(Path::new("a/../b"))
+            .flatten()
+            .map(|c| c.as_ref().to_path_buf())
+            .collect();
+        assert_eq!(names, vec![PathBuf::from("a")]);
+    }
+
+    #[test]
+    fn new_with_allowed_extensions_matches_case_insensitively_and_rejects_others() {
+        let allowed = ["txt", "md"];
+        assert!(SinglePathComponent::new_with_allowed_extensions(
+            "foo.TXT",
+            PathRules::UNRESTRICTED,
+            &allowed
+        )
+        .is_some());
+        assert!(SinglePathComponent::new_with_allowed_extensions(
+            "foo.exe",
+            PathRules::UNRESTRICTED,
+            &allowed
+        )
+        .is_none());
+        assert!(SinglePathComponent::new_with_allowed_extensions(
+            "foo",
+            PathRules::UNRESTRICTED,
+            &allowed
+        )
+        .is_none());
+    }
+}